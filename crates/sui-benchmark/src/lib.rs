@@ -23,7 +23,7 @@ use sui_types::{
     base_types::ObjectID,
     committee::{Committee, EpochId},
     messages::{CertifiedTransactionEffects, QuorumDriverResponse, Transaction},
-    object::{Object, ObjectRead},
+    object::Object,
     SUI_SYSTEM_STATE_OBJECT_ID,
 };
 use sui_types::{
@@ -192,10 +192,10 @@ impl LocalValidatorAggregatorProxy {
 impl ValidatorProxy for LocalValidatorAggregatorProxy {
     async fn get_object(&self, object_id: ObjectID) -> Result<Object, anyhow::Error> {
         let auth_agg = self.qd.authority_aggregator().load();
-        match auth_agg.get_object_info_execute(object_id).await? {
-            ObjectRead::Exists(_, object, _) => Ok(object),
-            other => bail!("object {object_id} does not exist: {:?}", other),
-        }
+        Ok(auth_agg
+            .get_object_info_execute(object_id)
+            .await?
+            .into_object()?)
     }
 
     async fn execute_transaction(
@@ -9,11 +9,12 @@ use crate::node::{
 use crate::p2p::{P2pConfig, SeedPeer};
 use crate::{builder, genesis, utils, Config, NodeConfig, ValidatorInfo};
 use fastcrypto::traits::KeyPair;
+use mysten_network::multiaddr::to_socket_addr;
 use rand::rngs::OsRng;
 use rand::RngCore;
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
-use std::net::{IpAddr, SocketAddr};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr, TcpListener, UdpSocket};
 use std::num::NonZeroUsize;
 use std::path::{Path, PathBuf};
 use sui_types::committee::Committee;
@@ -70,6 +71,52 @@ impl NetworkConfig {
     pub fn fullnode_config_builder(&self) -> FullnodeConfigBuilder<'_> {
         FullnodeConfigBuilder::new(self)
     }
+
+    /// Checks that every address the validators will listen on can be bound, so that a port
+    /// conflict is reported up front instead of partway through starting the network. The
+    /// JSON-RPC and admin interface addresses are not checked, since validators started this
+    /// way do not serve them.
+    pub fn validate_ports_available(&self) -> anyhow::Result<()> {
+        let localhost = |port| SocketAddr::from((Ipv4Addr::LOCALHOST, port));
+        let mut tcp_addresses = Vec::new();
+        let mut udp_addresses = Vec::new();
+        for config in &self.validator_configs {
+            tcp_addresses.extend(to_socket_addr(&config.network_address).ok());
+            tcp_addresses.push(config.metrics_address);
+            if let Some(consensus_config) = &config.consensus_config {
+                tcp_addresses.extend(to_socket_addr(&consensus_config.address).ok());
+                let admin = &consensus_config.narwhal_config.network_admin_server;
+                tcp_addresses.push(localhost(admin.primary_network_admin_server_port));
+                tcp_addresses.push(localhost(admin.worker_network_admin_server_base_port));
+            }
+            udp_addresses.push(config.p2p_config.listen_address);
+        }
+        for validator in self.validator_set() {
+            udp_addresses.extend(utils::udp_multiaddr_to_listen_address(
+                &validator.narwhal_primary_address,
+            ));
+            udp_addresses.extend(utils::udp_multiaddr_to_listen_address(
+                &validator.narwhal_worker_address,
+            ));
+        }
+
+        let unavailable: Vec<_> = tcp_addresses
+            .into_iter()
+            .filter(|address| TcpListener::bind(address).is_err())
+            .chain(
+                udp_addresses
+                    .into_iter()
+                    .filter(|address| UdpSocket::bind(address).is_err()),
+            )
+            .collect();
+        if !unavailable.is_empty() {
+            anyhow::bail!(
+                "The following validator addresses are already in use: {:?}",
+                unavailable
+            );
+        }
+        Ok(())
+    }
 }
 
 pub struct FullnodeConfigBuilder<'a> {
@@ -244,3 +291,31 @@ impl<'a> FullnodeConfigBuilder<'a> {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::net::{TcpListener, UdpSocket};
+
+    use crate::utils;
+
+    #[test]
+    fn validate_ports_available() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let network_config = crate::builder::ConfigBuilder::new(&dir).build();
+        network_config.validate_ports_available().unwrap();
+
+        let taken_tcp = network_config.validator_configs()[0].metrics_address;
+        let _listener = TcpListener::bind(taken_tcp).unwrap();
+        let taken_udp = utils::udp_multiaddr_to_listen_address(
+            &network_config.validator_set()[0].narwhal_primary_address,
+        )
+        .unwrap();
+        let _socket = UdpSocket::bind(taken_udp).unwrap();
+        let err = network_config
+            .validate_ports_available()
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains(&taken_tcp.to_string()));
+        assert!(err.contains(&taken_udp.to_string()));
+    }
+}
@@ -182,9 +182,11 @@ pub struct QuorumExecuteCertificateError {
 
 #[derive(Error, Debug)]
 #[error(
-    "Failed to execute transaction on a quorum of validators to form a transaction certificate. Locked objects: {:?}. Validator errors: {:?}",
+    "Failed to execute transaction on a quorum of validators to form a transaction certificate. Locked objects: {:?}. Validator errors: {:?}. Signed by: {:?}. No response from: {:?}",
     conflicting_tx_digests,
     errors,
+    signed_by,
+    no_response_from,
 )]
 pub struct QuorumSignTransactionError {
     pub total_stake: StakeUnit,
@@ -192,6 +194,24 @@ pub struct QuorumSignTransactionError {
     pub errors: Vec<(SuiError, Vec<AuthorityName>, StakeUnit)>,
     pub conflicting_tx_digests:
         BTreeMap<TransactionDigest, (Vec<(AuthorityName, ObjectRef)>, StakeUnit)>,
+    /// Authorities that signed the transaction.
+    pub signed_by: Vec<AuthorityName>,
+    /// Authorities we had not heard back from, either because their request timed out or
+    /// because we gave up on forming a certificate before they answered.
+    pub no_response_from: Vec<AuthorityName>,
+}
+
+/// The result of a quorum read of an object, along with how strongly the committee agreed on it.
+#[derive(Debug)]
+pub struct QuorumObjectInfo {
+    pub object_read: ObjectRead,
+    /// Total stake of the authorities that returned `object_read`. For `ObjectRead::NotExists`
+    /// this is the stake of the authorities that reported not knowing the object.
+    pub agreed_stake: StakeUnit,
+    /// Authorities that responded with a different version or state of the object.
+    pub dissenting_authorities: Vec<AuthorityName>,
+    /// Authorities whose request failed, so they neither agree nor dissent.
+    pub errored_authorities: Vec<(AuthorityName, SuiError)>,
 }
 
 struct ProcessTransactionState {
@@ -576,7 +596,8 @@ where
         // The async function that takes an accumulated state, and a new result for V from an
         // authority and returns a result to a ReduceOutput state.
         reduce_result: FReduce,
-        // The initial timeout applied to all
+        // The initial timeout applied to all. This bounds the wait for the next response, while
+        // each individual request is bounded by TimeoutConfig::authority_request_timeout.
         initial_timeout: Duration,
     ) -> Result<S, SuiError>
     where
@@ -616,20 +637,26 @@ where
         ) -> AsyncResult<'a, ReduceOutput<S>, SuiError>,
     {
         let authorities_shuffled = self.committee.shuffle_by_stake(authority_preferences, None);
+        let authority_request_timeout = self.timeouts.authority_request_timeout;
 
-        // First, execute in parallel for each authority FMap.
+        // First, execute in parallel for each authority FMap. Each request is bounded by
+        // authority_request_timeout, so a single slow authority is reported to FReduce as a
+        // TimeoutError instead of holding up the whole operation.
         let mut responses: futures::stream::FuturesUnordered<_> = authorities_shuffled
             .iter()
             .map(|name| {
                 let client = &self.authority_clients[name];
                 let execute = map_each_authority.clone();
                 monitored_future!(async move {
-                    (
-                        *name,
-                        execute(*name, client)
-                            .instrument(tracing::trace_span!("quorum_map_auth", authority =? name.concise()))
-                            .await,
+                    let result = timeout(
+                        authority_request_timeout,
+                        execute(*name, client).instrument(
+                            tracing::trace_span!("quorum_map_auth", authority =? name.concise()),
+                        ),
                     )
+                    .await
+                    .unwrap_or(Err(SuiError::TimeoutError));
+                    (*name, result)
                 })
             })
             .collect();
@@ -928,7 +955,7 @@ where
         &self,
         minimal_epoch: EpochId,
     ) -> SuiResult<CommitteeWithNetAddresses> {
-        let (aggregate_object_info, _certificates) =
+        let (aggregate_object_info, _certificates, _not_found, _errors) =
             // Skip committee check because this call usually happens when there's a potential new epoch
             self.get_object_by_id(SUI_SYSTEM_STATE_OBJECT_ID, true).await?;
 
@@ -1002,6 +1029,10 @@ where
     /// pair to the content of the object as well as a list of authorities that responded this
     /// pair.
     /// The second part of the return value is a map from transaction digest to the cert.
+    ///
+    /// Responses are folded into the result as they arrive, so only one copy of the object is
+    /// retained per unique (ObjectRef, TransactionDigest) pair rather than one per authority.
+    /// This keeps memory bounded for large objects regardless of the committee size.
     async fn get_object_by_id(
         &self,
         object_id: ObjectID,
@@ -1017,6 +1048,8 @@ where
                 ),
             >,
             HashMap<TransactionDigest, VerifiedCertificate>,
+            Vec<AuthorityName>,
+            Vec<(AuthorityName, SuiError)>,
         ),
         SuiError,
     > {
@@ -1024,8 +1057,75 @@ where
         struct GetObjectByIDRequestState {
             good_weight: StakeUnit,
             bad_weight: StakeUnit,
-            responses: Vec<(AuthorityName, SuiResult<VerifiedObjectInfoResponse>)>,
+            errors: Vec<(AuthorityName, SuiError)>,
+            // Authorities that responded without knowing the object
+            not_found: Vec<AuthorityName>,
+            object_map: BTreeMap<
+                (ObjectRef, TransactionDigest),
+                (
+                    Option<Object>,
+                    Option<MoveStructLayout>,
+                    Vec<(AuthorityName, Option<VerifiedSignedTransaction>)>,
+                ),
+            >,
+            certificates: HashMap<TransactionDigest, VerifiedCertificate>,
+        }
+
+        impl GetObjectByIDRequestState {
+            fn add_response(&mut self, name: AuthorityName, response: VerifiedObjectInfoResponse) {
+                let ObjectInfoResponse {
+                    parent_certificate,
+                    requested_object_reference,
+                    object_and_lock,
+                } = response;
+
+                // Extract the object_ref and transaction digest that will be used as keys
+                let object_ref = if let Some(object_ref) = requested_object_reference {
+                    object_ref
+                } else {
+                    // The object has never been seen on this authority
+                    self.not_found.push(name);
+                    return;
+                };
+
+                let (transaction_digest, cert_option) = if let Some(cert) = parent_certificate {
+                    (*cert.digest(), Some(cert))
+                } else {
+                    (TransactionDigest::genesis(), None)
+                };
+
+                // Extract an optional object to be used in the value, note that the object can be
+                // None if the object was deleted at this authority
+                //
+                // NOTE: here we could also be gathering the locked transactions to see if we could make a cert.
+                let (object_option, signed_transaction_option, layout_option) =
+                    if let Some(ObjectResponse {
+                        object,
+                        lock,
+                        layout,
+                    }) = object_and_lock
+                    {
+                        (Some(object), lock, layout)
+                    } else {
+                        (None, None, None)
+                    };
+
+                // Update the map with the information from this authority. If this pair has
+                // already been seen, the object and layout of this response are dropped here.
+                // TODO: if `(object_ref, transaction_digest)` is already seen, need to verify
+                // the existing value matches the old value.
+                let entry = self
+                    .object_map
+                    .entry((object_ref, transaction_digest))
+                    .or_insert((object_option, layout_option, Vec::new()));
+                entry.2.push((name, signed_transaction_option));
+
+                if let Some(cert) = cert_option {
+                    self.certificates.entry(*cert.digest()).or_insert(cert);
+                }
+            }
         }
+
         let initial_state = GetObjectByIDRequestState::default();
         let threshold = self.committee.quorum_threshold();
         let validity = self.committee.validity_threshold();
@@ -1056,24 +1156,19 @@ where
                         // object is final if it is on 2f+1 good nodes, and any set of 2f+1 intersects with this, so
                         // after we have 2f+1 of stake (good or bad) we should get a response with the object.
                         state.good_weight += weight;
-                        let is_err = result.is_err();
-                        state.responses.push((name, result));
-
-                        if is_err {
-                            // We also keep an error stake counter, and if it is larger than f+1 we return an error,
-                            // since either there are too many faulty authorities or we are not connected to the network.
-                            state.bad_weight += weight;
-                            if state.bad_weight > validity {
-                                return Err(SuiError::TooManyIncorrectAuthorities {
-                                    errors: state
-                                        .responses
-                                        .into_iter()
-                                        .filter_map(|(name, response)| {
-                                            response.err().map(|err| (name, err))
-                                        })
-                                        .collect(),
-                                    action: "get_object_by_id".to_string(),
-                                });
+                        match result {
+                            Ok(response) => state.add_response(name, response),
+                            Err(err) => {
+                                // We also keep an error stake counter, and if it is larger than f+1 we return an error,
+                                // since either there are too many faulty authorities or we are not connected to the network.
+                                state.errors.push((name, err));
+                                state.bad_weight += weight;
+                                if state.bad_weight > validity {
+                                    return Err(SuiError::TooManyIncorrectAuthorities {
+                                        errors: state.errors,
+                                        action: "get_object_by_id".to_string(),
+                                    });
+                                }
                             }
                         }
 
@@ -1094,72 +1189,12 @@ where
             )
             .await?;
 
-        let mut error_list = Vec::new();
-        let mut object_map = BTreeMap::<
-            (ObjectRef, TransactionDigest),
-            (
-                Option<Object>,
-                Option<MoveStructLayout>,
-                Vec<(AuthorityName, Option<VerifiedSignedTransaction>)>,
-            ),
-        >::new();
-        let mut certificates = HashMap::new();
-
-        for (name, result) in final_state.responses {
-            if let Ok(ObjectInfoResponse {
-                parent_certificate,
-                requested_object_reference,
-                object_and_lock,
-            }) = result
-            {
-                // Extract the object_ref and transaction digest that will be used as keys
-                let object_ref = if let Some(object_ref) = requested_object_reference {
-                    object_ref
-                } else {
-                    // The object has never been seen on this authority, so we skip
-                    continue;
-                };
-
-                let (transaction_digest, cert_option) = if let Some(cert) = parent_certificate {
-                    (*cert.digest(), Some(cert))
-                } else {
-                    (TransactionDigest::genesis(), None)
-                };
-
-                // Extract an optional object to be used in the value, note that the object can be
-                // None if the object was deleted at this authority
-                //
-                // NOTE: here we could also be gathering the locked transactions to see if we could make a cert.
-                let (object_option, signed_transaction_option, layout_option) =
-                    if let Some(ObjectResponse {
-                        object,
-                        lock,
-                        layout,
-                    }) = object_and_lock
-                    {
-                        (Some(object), lock, layout)
-                    } else {
-                        (None, None, None)
-                    };
-
-                // Update the map with the information from this authority
-                // TODO: if `(object_ref, transaction_digest)` is already seen, need to verify
-                // the existing value matches the old value.
-                let entry = object_map
-                    .entry((object_ref, transaction_digest))
-                    .or_insert((object_option, layout_option, Vec::new()));
-                entry.2.push((name, signed_transaction_option));
-
-                if let Some(cert) = cert_option {
-                    certificates.insert(*cert.digest(), cert);
-                }
-            } else {
-                error_list.push((name, result));
-            }
-        }
-
-        // TODO: return the errors too
-        Ok((object_map, certificates))
+        Ok((
+            final_state.object_map,
+            final_state.certificates,
+            final_state.not_found,
+            final_state.errors,
+        ))
     }
 
     /// Submits the transaction to a quorum of validators to make a certificate.
@@ -1263,12 +1298,28 @@ where
         state = Self::record_non_quorum_effects_maybe(tx_digest, state);
 
         // If we have some certificate return it, or return an error.
-        let cert = state.certificate.ok_or(QuorumSignTransactionError {
-            total_stake: self.committee.total_votes,
-            good_stake: state.good_stake,
-            errors: state.errors,
-            conflicting_tx_digests: state.conflicting_tx_digests,
-        })?;
+        let Some(cert) = state.certificate else {
+            let signed_by: Vec<_> = state.signatures.iter().map(|s| s.authority).collect();
+            let no_response_from = self
+                .committee
+                .names()
+                .filter(|name| {
+                    !signed_by.contains(name)
+                        && !state.errors.iter().any(|(err, names, _)| {
+                            !matches!(err, SuiError::TimeoutError) && names.contains(name)
+                        })
+                })
+                .copied()
+                .collect();
+            return Err(QuorumSignTransactionError {
+                total_stake: self.committee.total_votes,
+                good_stake: state.good_stake,
+                errors: state.errors,
+                conflicting_tx_digests: state.conflicting_tx_digests,
+                signed_by,
+                no_response_from,
+            });
+        };
 
         if let Some(effects_cert) = state.effects_map.get_message_cert() {
             Ok(ProcessTransactionResult::Executed(cert, effects_cert))
@@ -1607,8 +1658,18 @@ where
     }
 
     pub async fn get_object_info_execute(&self, object_id: ObjectID) -> SuiResult<ObjectRead> {
-        let (object_map, _cert_map) = self.get_object_by_id(object_id, false).await?;
+        Ok(self.quorum_object_info(object_id).await?.object_read)
+    }
+
+    /// Read the latest state of an object from a quorum of authorities, returning it together
+    /// with the stake that agreed on it, the authorities that reported something else and the
+    /// authorities that failed to answer. Callers that need stronger assurance than f+1
+    /// agreement can inspect `agreed_stake`.
+    pub async fn quorum_object_info(&self, object_id: ObjectID) -> SuiResult<QuorumObjectInfo> {
+        let (object_map, _cert_map, not_found, errored_authorities) =
+            self.get_object_by_id(object_id, false).await?;
         let mut object_ref_stack: Vec<_> = object_map.into_iter().collect();
+        let mut dissenting_authorities = Vec::new();
 
         while let Some(((obj_ref, _tx_digest), (obj_option, layout_option, authorities))) =
             object_ref_stack.pop()
@@ -1621,19 +1682,133 @@ where
             // If we have f+1 stake telling us of the latest version of the object, we just accept
             // it.
             if stake >= self.committee.validity_threshold() {
-                match obj_option {
-                    Some(obj) => {
-                        return Ok(ObjectRead::Exists(obj_ref, obj, layout_option));
-                    }
-                    None => {
-                        // TODO: Figure out how to find out object being wrapped instead of deleted.
-                        return Ok(ObjectRead::Deleted(obj_ref));
-                    }
+                dissenting_authorities.extend(
+                    object_ref_stack
+                        .into_iter()
+                        .flat_map(|(_, (_, _, authorities))| authorities)
+                        .map(|(name, _)| name),
+                );
+                dissenting_authorities.extend(not_found);
+                let object_read = match obj_option {
+                    Some(obj) => ObjectRead::Exists(obj_ref, obj, layout_option),
+                    // TODO: Figure out how to find out object being wrapped instead of deleted.
+                    None => ObjectRead::Deleted(obj_ref),
                 };
+                return Ok(QuorumObjectInfo {
+                    object_read,
+                    agreed_stake: stake,
+                    dissenting_authorities,
+                    errored_authorities,
+                });
             }
+            dissenting_authorities.extend(authorities.into_iter().map(|(name, _)| name));
+        }
+
+        Ok(QuorumObjectInfo {
+            object_read: ObjectRead::NotExists(object_id),
+            agreed_stake: not_found
+                .iter()
+                .map(|name| self.committee.weight(name))
+                .sum(),
+            dissenting_authorities,
+            errored_authorities,
+        })
+    }
+
+    /// Read an object at a specific version from the authorities, for debugging tools such as
+    /// `sui-tool`. This relies on `PastObjectInfoDebug` requests, so it only works while
+    /// validators still store old object versions and production code must not depend on it.
+    ///
+    /// The object is returned once f+1 stake agrees on its contents at that version. If f+1
+    /// stake knows the version but can no longer return its contents, this fails with
+    /// `SuiError::ObjectVersionPruned`; otherwise it fails with `SuiError::ObjectNotFound`.
+    pub async fn get_past_object_info_debug(
+        &self,
+        object_id: ObjectID,
+        version: SequenceNumber,
+    ) -> SuiResult<ObjectRead> {
+        #[derive(Default)]
+        struct GetPastObjectState {
+            bad_weight: StakeUnit,
+            // Stake of the authorities that know this version but no longer have its contents
+            pruned_weight: StakeUnit,
+            errors: Vec<(AuthorityName, SuiError)>,
+            objects: BTreeMap<ObjectDigest, (Object, Option<MoveStructLayout>, StakeUnit)>,
+            agreed: Option<(Object, Option<MoveStructLayout>, StakeUnit)>,
         }
 
-        Ok(ObjectRead::NotExists(object_id))
+        let validity = self.committee.validity_threshold();
+        let final_state = self
+            .quorum_map_then_reduce_with_timeout(
+                GetPastObjectState::default(),
+                |_name, client| {
+                    Box::pin(async move {
+                        let request = ObjectInfoRequest {
+                            object_id,
+                            request_kind: ObjectInfoRequestKind::PastObjectInfoDebug(
+                                version,
+                                Some(ObjectFormatOptions::default()),
+                            ),
+                        };
+                        client.handle_object_info_request(request, false).await
+                    })
+                },
+                |mut state, name, weight, result| {
+                    Box::pin(async move {
+                        match result {
+                            Ok(response) => match response.object_and_lock {
+                                Some(ObjectResponse { object, layout, .. }) => {
+                                    if object.version() == version {
+                                        let digest = object.digest();
+                                        let entry = state
+                                            .objects
+                                            .entry(digest)
+                                            .or_insert((object, layout, 0));
+                                        entry.2 += weight;
+                                        if entry.2 >= validity {
+                                            state.agreed = state.objects.remove(&digest);
+                                            return Ok(ReduceOutput::End(state));
+                                        }
+                                    }
+                                }
+                                None if response.requested_object_reference.is_some() => {
+                                    state.pruned_weight += weight;
+                                }
+                                None => {}
+                            },
+                            Err(err) => {
+                                state.errors.push((name, err));
+                                state.bad_weight += weight;
+                                if state.bad_weight > validity {
+                                    return Err(SuiError::TooManyIncorrectAuthorities {
+                                        errors: state.errors,
+                                        action: "get_past_object_info_debug".to_string(),
+                                    });
+                                }
+                            }
+                        }
+                        Ok(ReduceOutput::Continue(state))
+                    })
+                },
+                // A long timeout before we hear back from a quorum
+                self.timeouts.pre_quorum_timeout,
+            )
+            .await?;
+
+        match final_state.agreed {
+            Some((object, layout, _)) => Ok(ObjectRead::Exists(
+                object.compute_object_reference(),
+                object,
+                layout,
+            )),
+            None if final_state.pruned_weight >= validity => {
+                Err(SuiError::ObjectVersionPruned { object_id, version })
+            }
+            None => Err(SuiError::ObjectNotFound {
+                object_id,
+                version: Some(version),
+            }),
+        }
     }
 
     /// This function tries to get SignedTransaction OR CertifiedTransaction from
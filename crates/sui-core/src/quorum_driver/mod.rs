@@ -284,6 +284,7 @@ where
                 good_stake,
                 errors: _errors,
                 conflicting_tx_digests,
+                ..
             }) if !conflicting_tx_digests.is_empty() => {
                 self.metrics
                     .total_err_process_tx_responses_with_nonzero_conflicting_transactions
@@ -821,7 +822,7 @@ fn convert_to_quorum_driver_error_if_non_retryable<A>(
             total_stake,
             good_stake: _,
             errors,
-            conflicting_tx_digests: _,
+            ..
         })
         | QuorumDriverInternalError::CertificateError(QuorumExecuteCertificateError {
             total_stake,
@@ -287,7 +287,9 @@ impl<C> SafeClient<C> {
                     reason: "Object ID mismatch".to_string()
                 }
             );
-            if let ObjectInfoRequestKind::PastObjectInfo(requested_version) = &request.request_kind
+            if let ObjectInfoRequestKind::PastObjectInfo(requested_version)
+            | ObjectInfoRequestKind::PastObjectInfoDebug(requested_version, _) =
+                &request.request_kind
             {
                 fp_ensure!(
                     version == requested_version,
@@ -305,24 +307,33 @@ impl<C> SafeClient<C> {
                 lock,
                 layout,
             } = object_and_lock;
-            // We should only be returning the object and lock data if requesting the latest object info.
-            fp_ensure!(
-                matches!(
-                    request.request_kind,
-                    ObjectInfoRequestKind::LatestObjectInfo(_)
-                ),
-                SuiError::ByzantineAuthoritySuspicion {
-                    authority: self.address,
-                    reason:
-                        "Object and lock data returned when request kind is not LatestObjectInfo"
-                            .to_string()
+            // We should only be returning the object and lock data if requesting the latest object
+            // info, or the object alone if requesting a past version with its content.
+            match request.request_kind {
+                ObjectInfoRequestKind::LatestObjectInfo(_) => {}
+                ObjectInfoRequestKind::PastObjectInfoDebug(requested_version, _) => {
+                    fp_ensure!(
+                        object.version() == requested_version && lock.is_none(),
+                        SuiError::ByzantineAuthoritySuspicion {
+                            authority: self.address,
+                            reason: "Past object version mismatch or lock data returned"
+                                .to_string()
+                        }
+                    );
                 }
-            );
+                ObjectInfoRequestKind::PastObjectInfo(_) => {
+                    return Err(SuiError::ByzantineAuthoritySuspicion {
+                        authority: self.address,
+                        reason: "Object and lock data returned when request kind is PastObjectInfo"
+                            .to_string(),
+                    });
+                }
+            }
 
             match requested_object_reference {
                 Some(obj_ref) => {
-                    // Since we are requesting the latest version, we should validate that if the object's
-                    // reference actually match with the one from the responded object reference.
+                    // We should validate that the object's reference actually matches the
+                    // responded object reference, for the latest as well as a past version.
                     fp_ensure!(
                         object.compute_object_reference() == obj_ref,
                         SuiError::ByzantineAuthoritySuspicion {
@@ -333,8 +344,8 @@ impl<C> SafeClient<C> {
                     );
                 }
                 None => {
-                    // Since we are returning the object for the latest version,
-                    // we must also have the requested object reference in the response.
+                    // Since we are returning the object, we must also have the requested
+                    // object reference in the response.
                     // Otherwise the authority has inconsistent data.
                     return Err(SuiError::ByzantineAuthoritySuspicion {
                         authority: self.address,
@@ -172,6 +172,7 @@ pub struct MockAuthorityApi {
     count: Arc<Mutex<u32>>,
     handle_committee_info_request_result: Option<SuiResult<CommitteeInfoResponse>>,
     handle_object_info_request_result: Option<SuiResult<ObjectInfoResponse>>,
+    handle_transaction_result: Option<SuiResult<TransactionInfoResponse>>,
 }
 
 impl MockAuthorityApi {
@@ -181,6 +182,7 @@ impl MockAuthorityApi {
             count,
             handle_committee_info_request_result: None,
             handle_object_info_request_result: None,
+            handle_transaction_result: None,
         }
     }
     pub fn set_handle_committee_info_request_result(
@@ -193,6 +195,10 @@ impl MockAuthorityApi {
     pub fn set_handle_object_info_request(&mut self, result: SuiResult<ObjectInfoResponse>) {
         self.handle_object_info_request_result = Some(result);
     }
+
+    pub fn set_handle_transaction_result(&mut self, result: SuiResult<TransactionInfoResponse>) {
+        self.handle_transaction_result = Some(result);
+    }
 }
 
 #[async_trait]
@@ -202,7 +208,8 @@ impl AuthorityAPI for MockAuthorityApi {
         &self,
         _transaction: Transaction,
     ) -> Result<TransactionInfoResponse, SuiError> {
-        unreachable!();
+        tokio::time::sleep(self.delay).await;
+        self.handle_transaction_result.clone().unwrap()
     }
 
     /// Execute a certificate.
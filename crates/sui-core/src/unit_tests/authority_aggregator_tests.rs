@@ -29,8 +29,10 @@ use crate::test_authority_clients::{
     MockAuthorityApi,
 };
 use crate::test_utils::init_local_authorities;
+use sui_types::storage::ObjectKey;
 use sui_types::utils::to_sender_signed_transaction;
 use tokio::time::Instant;
+use typed_store::Map;
 
 #[cfg(msim)]
 use sui_simulator::configs::constant_latency_ms;
@@ -559,6 +561,143 @@ async fn test_process_transaction_fault_fail() {
     );
 }
 
+#[tokio::test]
+async fn test_quorum_object_info() {
+    let (addr1, _): (_, AccountKeyPair) = get_key_pair();
+    let gas_object = Object::with_owner_for_testing(addr1);
+    let (authorities, _, genesis, _) = init_local_authorities(4, vec![gas_object.clone()]).await;
+    let gas_object = genesis.object(gas_object.id()).unwrap();
+
+    let info = authorities
+        .quorum_object_info(gas_object.id())
+        .await
+        .unwrap();
+    assert!(
+        matches!(info.object_read, ObjectRead::Exists(obj_ref, _, _) if obj_ref == gas_object.compute_object_reference())
+    );
+    assert_eq!(info.agreed_stake, authorities.committee.total_votes);
+    assert!(info.dissenting_authorities.is_empty());
+    assert!(info.errored_authorities.is_empty());
+
+    // Every authority reports the object as unknown, and that stake counts as agreement.
+    let info = authorities
+        .quorum_object_info(ObjectID::random())
+        .await
+        .unwrap();
+    assert!(matches!(info.object_read, ObjectRead::NotExists(_)));
+    assert!(info.agreed_stake >= authorities.committee.quorum_threshold());
+    assert!(info.dissenting_authorities.is_empty());
+    assert!(info.errored_authorities.is_empty());
+}
+
+#[tokio::test]
+async fn test_get_past_object_info_debug() {
+    let (addr1, _): (_, AccountKeyPair) = get_key_pair();
+    let gas_object = Object::with_owner_for_testing(addr1);
+    let (authorities, _, genesis, _) = init_local_authorities(4, vec![gas_object.clone()]).await;
+    let gas_object = genesis.object(gas_object.id()).unwrap();
+
+    let read = authorities
+        .get_past_object_info_debug(gas_object.id(), gas_object.version())
+        .await
+        .unwrap();
+    assert!(
+        matches!(read, ObjectRead::Exists(obj_ref, _, _) if obj_ref == gas_object.compute_object_reference())
+    );
+
+    // No authority has this version, so it is reported as not found rather than as a fault.
+    let missing_version = SequenceNumber::from_u64(gas_object.version().value() + 1);
+    let err = authorities
+        .get_past_object_info_debug(gas_object.id(), missing_version)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err,
+        SuiError::ObjectNotFound {
+            object_id: gas_object.id(),
+            version: Some(missing_version),
+        }
+    );
+
+    // Once too few authorities keep the contents of the version to reach f+1, while the rest
+    // still know it existed, it is reported as pruned.
+    for client in authorities.authority_clients.values().take(3) {
+        client
+            .authority_client()
+            .state
+            .database
+            .perpetual_tables
+            .objects
+            .remove(&ObjectKey(gas_object.id(), gas_object.version()))
+            .unwrap();
+    }
+    let err = authorities
+        .get_past_object_info_debug(gas_object.id(), gas_object.version())
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err,
+        SuiError::ObjectVersionPruned {
+            object_id: gas_object.id(),
+            version: gas_object.version(),
+        }
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_quorum_map_and_reduce_per_authority_timeout() {
+    telemetry_subscribers::init_for_testing();
+
+    let count = Arc::new(Mutex::new(0));
+    let (authorities, authorities_vec, clients) = get_authorities(count, 4);
+    let committee = Committee::new(0, ProtocolVersion::MIN, authorities).unwrap();
+    let committee_store = Arc::new(CommitteeStore::new_for_testing(&committee));
+    let agg = AuthorityAggregator::new_with_timeouts(
+        committee,
+        committee_store,
+        clients,
+        &Registry::new(),
+        TimeoutConfig {
+            authority_request_timeout: Duration::from_millis(100),
+            ..Default::default()
+        },
+    );
+    let slow_authority = authorities_vec[0].0;
+
+    let start = Instant::now();
+    let (ok, timed_out) = agg
+        .quorum_map_then_reduce_with_timeout(
+            (0, vec![]),
+            |name, _client| {
+                Box::pin(async move {
+                    if name == slow_authority {
+                        tokio::time::sleep(Duration::from_secs(60)).await;
+                    }
+                    Ok(())
+                })
+            },
+            |(mut ok, mut timed_out), name, _weight, result| {
+                Box::pin(async move {
+                    match result {
+                        Ok(()) => ok += 1,
+                        Err(SuiError::TimeoutError) => timed_out.push(name),
+                        Err(err) => return Err(err),
+                    }
+                    Ok(ReduceOutput::Continue((ok, timed_out)))
+                })
+            },
+            Duration::from_secs(30),
+        )
+        .await
+        .unwrap();
+
+    // The slow authority is abandoned after the per-request timeout, well before either the
+    // overall timeout or the slow request itself would complete.
+    assert_eq!(ok, 3);
+    assert_eq!(timed_out, vec![slow_authority]);
+    assert!(Instant::now() - start < Duration::from_secs(1));
+}
+
 #[tokio::test(start_paused = true)]
 async fn test_quorum_once_with_timeout() {
     telemetry_subscribers::init_for_testing();
@@ -1156,6 +1295,100 @@ async fn test_handle_transaction_response() {
     .await;
 }
 
+#[tokio::test(start_paused = true)]
+async fn test_process_transaction_reports_unresponsive_authorities() {
+    telemetry_subscribers::init_for_testing();
+
+    let count = Arc::new(Mutex::new(0));
+    let mut authorities = BTreeMap::new();
+    let mut clients = BTreeMap::new();
+    let mut authority_keys = Vec::new();
+    for _ in 0..4 {
+        let (_, sec): (_, AuthorityKeyPair) = get_key_pair();
+        let name: AuthorityName = sec.public().into();
+        authorities.insert(name, 1);
+        authority_keys.push((name, sec));
+    }
+
+    let (sender, sender_kp): (_, AccountKeyPair) = get_key_pair();
+    let tx = make_transfer_sui_transaction(
+        random_object_ref(),
+        SuiAddress::default(),
+        None,
+        sender,
+        &sender_kp,
+        None,
+    );
+
+    // Val-0 and Val-1 sign, Val-2 rejects and Val-3 would sign but never answers in time.
+    for (i, (name, secret)) in authority_keys.iter().enumerate() {
+        let delay = if i == 3 {
+            Duration::from_secs(3600)
+        } else {
+            Duration::from_millis(10)
+        };
+        let mut client = MockAuthorityApi::new(delay, count.clone());
+        if i == 2 {
+            client.set_handle_transaction_result(Err(SuiError::GenericAuthorityError {
+                error: "Mock rejection of handle_transaction".to_owned(),
+            }));
+        } else {
+            client.set_handle_transaction_result(Ok(TransactionInfoResponse::Signed(sign_tx(
+                tx.clone(),
+                0,
+                *name,
+                secret,
+            ))));
+        }
+        clients.insert(*name, client);
+    }
+    let signers: HashSet<_> = authority_keys[..2].iter().map(|(name, _)| *name).collect();
+    let rejecter = authority_keys[2].0;
+    let hung = authority_keys[3].0;
+
+    let process = |authority_request_timeout: u64, pre_quorum_timeout: u64| {
+        let mut agg = get_agg(authorities.clone(), clients.clone(), 0);
+        agg.timeouts.authority_request_timeout = Duration::from_secs(authority_request_timeout);
+        agg.timeouts.pre_quorum_timeout = Duration::from_secs(pre_quorum_timeout);
+        let tx = tx.clone();
+        async move {
+            match agg.process_transaction(tx).await {
+                Err(err) => err,
+                Ok(_) => panic!("Expected process_transaction to fail without a quorum"),
+            }
+        }
+    };
+
+    // The request to Val-3 times out first: it is reported both as a TimeoutError and as
+    // not having responded, while Val-2 only shows up among the errors.
+    let err = process(10, 60).await;
+    assert_eq!(
+        err.signed_by.iter().copied().collect::<HashSet<_>>(),
+        signers
+    );
+    assert_eq!(err.no_response_from, vec![hung]);
+    assert_eq!(err.good_stake, 2);
+    let mut errored: Vec<_> = err
+        .errors
+        .iter()
+        .map(|(err, names, _)| (matches!(err, SuiError::TimeoutError), names.clone()))
+        .collect();
+    errored.sort();
+    assert_eq!(errored, vec![(false, vec![rejecter]), (true, vec![hung])]);
+
+    // We give up waiting before the request to Val-3 times out, so there is no TimeoutError
+    // for it, but it is still reported as not having responded.
+    let err = process(60, 10).await;
+    assert_eq!(
+        err.signed_by.iter().copied().collect::<HashSet<_>>(),
+        signers
+    );
+    assert_eq!(err.no_response_from, vec![hung]);
+    assert_eq!(err.errors.len(), 1);
+    assert_eq!(err.errors[0].1, vec![rejecter]);
+    assert!(!matches!(err.errors[0].0, SuiError::TimeoutError));
+}
+
 async fn assert_resp_err<F>(
     agg: &AuthorityAggregator<HandleTransactionTestAuthorityClient>,
     tx: VerifiedTransaction,
@@ -1169,10 +1402,13 @@ async fn assert_resp_err<F>(
             good_stake,
             errors,
             conflicting_tx_digests,
+            signed_by,
+            ..
         }) => {
             assert_eq!(total_stake, 4);
             assert_eq!(good_stake, 0);
             assert!(conflicting_tx_digests.is_empty());
+            assert!(signed_by.is_empty());
             assert!(errors.iter().map(|e| &e.0).all(checker));
         }
         other => {
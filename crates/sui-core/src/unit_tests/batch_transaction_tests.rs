@@ -71,14 +71,7 @@ async fn test_batch_transaction_ok() -> anyhow::Result<()> {
         .iter()
         .all(|(_, owner)| owner == &Owner::AddressOwner(sender)));
     // N of the objects should now be owned by recipient.
-    assert_eq!(
-        effects
-            .mutated
-            .iter()
-            .filter(|(_, owner)| owner == &Owner::AddressOwner(recipient))
-            .count(),
-        N
-    );
+    assert_eq!(effects.objects_owned_by(recipient).len(), N);
 
     Ok(())
 }
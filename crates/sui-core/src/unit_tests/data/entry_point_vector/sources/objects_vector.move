@@ -5,6 +5,7 @@ module entry_point_vector::entry_point_vector {
     use sui::object::{Self, UID};
     use sui::transfer;
     use sui::tx_context::{Self, TxContext};
+    use std::option::Option;
     use std::vector;
 
     struct Obj has key, store {
@@ -73,6 +74,9 @@ module entry_point_vector::entry_point_vector {
         assert!(vector::length(&v) == 2, 0);
     }
 
+    public entry fun prim_option(_o: Option<u64>, _: &mut TxContext) {
+    }
+
     public entry fun obj_vec_empty(v: vector<Obj>, _: &mut TxContext) {
         vector::destroy_empty(v);
     }
@@ -31,7 +31,7 @@ use sui_types::messages_checkpoint::{
     CheckpointSummary,
 };
 use sui_types::move_package::normalize_modules;
-use sui_types::object::{Data, ObjectRead};
+use sui_types::object::Data;
 use sui_types::query::TransactionQuery;
 
 use tracing::debug;
@@ -300,13 +300,12 @@ impl RpcFullNodeReadApiServer for FullNodeApi {
             .await
             .map_err(|e| anyhow!("{e}"))?;
 
-        let normalized = match object_read {
-            ObjectRead::Exists(_obj_ref, object, _layout) => match object.data {
-                Data::Package(p) => normalize_modules(p.serialized_module_map().values())
-                    .map_err(|e| anyhow!("{e}")),
-                _ => Err(anyhow!("Object is not a package with ID {}", package)),
-            },
-            _ => Err(anyhow!("Package object does not exist with ID {}", package)),
+        let object = object_read.object().map_err(|e| anyhow!("{e}"))?;
+        let normalized = match &object.data {
+            Data::Package(p) => {
+                normalize_modules(p.serialized_module_map().values()).map_err(|e| anyhow!("{e}"))
+            }
+            _ => Err(anyhow!("Object is not a package with ID {}", package)),
         }?;
 
         let identifier = Identifier::new(function.as_str()).map_err(|e| anyhow!("{e}"))?;
@@ -458,14 +457,12 @@ pub async fn get_move_modules_by_package(
         .await
         .map_err(|e| anyhow!("{e}"))?;
 
-    Ok(match object_read {
-        ObjectRead::Exists(_obj_ref, object, _layout) => match object.data {
-            Data::Package(p) => {
-                normalize_modules(p.serialized_module_map().values()).map_err(|e| anyhow!("{e}"))
-            }
-            _ => Err(anyhow!("Object is not a package with ID {}", package)),
-        },
-        _ => Err(anyhow!("Package object does not exist with ID {}", package)),
+    let object = object_read.object().map_err(|e| anyhow!("{e}"))?;
+    Ok(match &object.data {
+        Data::Package(p) => {
+            normalize_modules(p.serialized_module_map().values()).map_err(|e| anyhow!("{e}"))
+        }
+        _ => Err(anyhow!("Object is not a package with ID {}", package)),
     }?)
 }
 
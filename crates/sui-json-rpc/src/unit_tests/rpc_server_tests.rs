@@ -13,9 +13,9 @@ use sui_framework_build::compiled_package::BuildConfig;
 use sui_json::SuiJsonValue;
 use sui_json_rpc_types::SuiObjectInfo;
 use sui_json_rpc_types::{
-    Balance, CoinPage, GetObjectDataResponse, SuiCoinMetadata, SuiEvent,
-    SuiExecuteTransactionResponse, SuiExecutionStatus, SuiTBlsSignObjectCommitmentType,
-    SuiTransactionResponse, TransactionBytes,
+    Balance, CoinPage, GetObjectDataResponse, RPCTransactionRequestParams, SuiCoinMetadata,
+    SuiEvent, SuiExecuteTransactionResponse, SuiExecutionStatus, SuiTBlsSignObjectCommitmentType,
+    SuiTransactionResponse, TransactionBytes, TransferObjectParams,
 };
 use sui_keys::keystore::{AccountKeystore, FileBasedKeystore, Keystore};
 use sui_types::balance::Supply;
@@ -89,6 +89,90 @@ async fn test_public_transfer_object() -> Result<(), anyhow::Error> {
     Ok(())
 }
 
+#[sim_test]
+async fn test_transfer_object_not_owned_by_signer() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+    let address = cluster.accounts[0];
+    let other = cluster.accounts[1];
+
+    let objects = http_client.get_objects_owned_by_address(address).await?;
+    let other_objects = http_client.get_objects_owned_by_address(other).await?;
+
+    // Transferring an object owned by someone else is rejected before signing.
+    let result = http_client
+        .transfer_object(
+            address,
+            other_objects.first().unwrap().object_id,
+            Some(objects.first().unwrap().object_id),
+            1000,
+            address,
+        )
+        .await;
+    assert!(result.unwrap_err().to_string().contains("not by signer"));
+
+    // So is paying gas with a coin owned by someone else.
+    let result = http_client
+        .transfer_object(
+            address,
+            objects.first().unwrap().object_id,
+            Some(other_objects.first().unwrap().object_id),
+            1000,
+            address,
+        )
+        .await;
+    assert!(result.unwrap_err().to_string().contains("not by signer"));
+
+    // Batched transfers go through the same ownership check.
+    let result = http_client
+        .batch_transaction(
+            address,
+            vec![RPCTransactionRequestParams::TransferObjectRequestParams(
+                TransferObjectParams {
+                    recipient: address,
+                    object_id: other_objects.first().unwrap().object_id,
+                },
+            )],
+            Some(objects.first().unwrap().object_id),
+            1000,
+            None,
+        )
+        .await;
+    assert!(result.unwrap_err().to_string().contains("not by signer"));
+    Ok(())
+}
+
+#[sim_test]
+async fn test_gas_object_used_as_move_call_argument() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+    let address = cluster.accounts.first().unwrap();
+    let objects = http_client.get_objects_owned_by_address(*address).await?;
+    let coin = objects.first().unwrap().object_id;
+
+    let result = http_client
+        .move_call(
+            *address,
+            SUI_FRAMEWORK_ADDRESS.into(),
+            "pay".to_string(),
+            "split".to_string(),
+            vec![parse_sui_type_tag("0x2::sui::SUI")?.into()],
+            vec![
+                SuiJsonValue::from_str(&coin.to_string())?,
+                SuiJsonValue::from_bcs_bytes(&bcs::to_bytes(&"20")?)?,
+            ],
+            Some(coin),
+            1000,
+            None,
+        )
+        .await;
+    assert!(result
+        .unwrap_err()
+        .to_string()
+        .contains("is also used as an input object"));
+    Ok(())
+}
+
 #[sim_test]
 async fn test_tbls_sign_randomness_object() -> Result<(), anyhow::Error> {
     let cluster = TestClusterBuilder::new().build().await?;
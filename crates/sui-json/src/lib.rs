@@ -89,6 +89,8 @@ impl SuiJsonValue {
         match json_value.clone() {
             // No checks needed for Bool and String
             JsonValue::Bool(_) | JsonValue::String(_) => (),
+            // Null is only meaningful as an `Option` call argument set to `none`
+            JsonValue::Null => (),
             JsonValue::Number(n) => {
                 // Must be castable to u64
                 if !n.is_u64() {
@@ -533,6 +535,14 @@ fn resolve_object_vec_arg(idx: usize, arg: &SuiJsonValue) -> Result<Vec<ObjectID
     }
 }
 
+fn is_std_option(view: &BinaryIndexedView, param: &SignatureToken) -> bool {
+    matches!(
+        param,
+        SignatureToken::StructInstantiation(idx, targs)
+            if targs.len() == 1 && sui_verifier::resolve_struct(view, *idx) == RESOLVED_STD_OPTION
+    )
+}
+
 fn resolve_call_arg(
     view: &BinaryIndexedView,
     type_args: &[TypeTag],
@@ -542,6 +552,14 @@ fn resolve_call_arg(
 ) -> Result<SuiJsonCallArg, anyhow::Error> {
     let (is_primitive, layout_opt) = primitive_type(view, type_args, param);
     if is_primitive {
+        // `Option<T>` is laid out as a vector of at most one element, so `null` is `none`. This
+        // has to be decided here, as the layout alone does not tell an option from a vector.
+        let none = SuiJsonValue(JsonValue::Array(vec![]));
+        let arg = if arg.0.is_null() && is_std_option(view, param) {
+            &none
+        } else {
+            arg
+        };
         match layout_opt {
             Some(layout) => {
                 return Ok(SuiJsonCallArg::Pure(arg.to_bcs_bytes(&layout).map_err(
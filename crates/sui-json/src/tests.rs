@@ -87,6 +87,8 @@ fn test_json_is_not_valid_sui_json() {
         (json!(-10)),
         // Not homogeneous
         (json!([[[9, 53, 434], [0], [300]], [], [300, 4, 5, 6, 7]])),
+        // Null not allowed inside arrays
+        (json!([1, null])),
     ];
 
     // Driver
@@ -107,6 +109,8 @@ fn test_json_is_valid_sui_json() {
         // Uint allowed
         (json!(100)),
         (json!([])),
+        // Null allowed at the top level, for option arguments
+        (json!(null)),
         // Homogeneous
         (json!([[[9, 53, 434], [0], [300]], [], [[332], [4, 5, 6, 7]]])),
     ];
@@ -147,6 +151,10 @@ fn test_basic_args_linter_pure_args_bad() {
              (Value::from(-19), MoveTypeLayout::U64),
              // Negative cannot be used as Unsigned
              (Value::from(-1), MoveTypeLayout::U8),
+             // Null is only valid for options
+             (json!(null), MoveTypeLayout::U64),
+             // The layout alone cannot tell an option from a vector, so null is rejected here
+             (json!(null), MoveTypeLayout::Vector(Box::new(MoveTypeLayout::U64))),
               // u8 vector from bad hex repr
             (
                 Value::from(bad_hex_val),
@@ -370,6 +378,12 @@ fn test_basic_args_linter_pure_args_good() {
             ])
             .unwrap(),
         ),
+        // Option some from a single element array
+        (
+            json!(["5"]),
+            MoveTypeLayout::Vector(Box::new(MoveTypeLayout::U64)),
+            bcs::to_bytes(&Some(5u64)).unwrap(),
+        ),
         // U32 deep nest, good
         (
             json!([[[9, 53, 434], [0], [300]], [], [[332], [4, 5, 6, 7]]]),
@@ -644,7 +658,7 @@ fn test_basic_args_linter_top_level() {
 
     let args = resolve_move_function_args(
         example_package,
-        module,
+        module.clone(),
         function,
         &[],
         args,
@@ -665,6 +679,49 @@ fn test_basic_args_linter_top_level() {
             ObjectID::from_hex_literal(&format!("0x{:02x}", object_id_raw2)).unwrap()
         );
     }
+
+    // Test with null as an option argument
+    let function = Identifier::new("prim_option").unwrap();
+
+    /*
+    Function signature:
+            public entry fun prim_option(_o: Option<u64>, _: &mut TxContext)
+     */
+    let args = vec![SuiJsonValue::new(Value::Null).unwrap()];
+
+    let args = resolve_move_function_args(
+        example_package,
+        module.clone(),
+        function,
+        &[],
+        args,
+        /* allow_arbitrary_function_call */ false,
+    )
+    .unwrap();
+
+    assert_eq!(
+        args[0],
+        SuiJsonCallArg::Pure(bcs::to_bytes(&None::<u64>).unwrap())
+    );
+
+    // Null is not accepted for a plain vector argument
+    let function = Identifier::new("prim_vec_len").unwrap();
+
+    /*
+    Function signature:
+            public entry fun prim_vec_len(v: vector<u64>, _: &mut TxContext)
+     */
+    let args = vec![SuiJsonValue::new(Value::Null).unwrap()];
+
+    assert!(resolve_move_function_args(
+        example_package,
+        module,
+        function,
+        &[],
+        args,
+        /* allow_arbitrary_function_call */ false,
+    )
+    .is_err());
 }
 
 #[test]
@@ -9,6 +9,7 @@ use crate::{
 use anyhow::Result;
 use std::path::PathBuf;
 use sui_config::genesis::Genesis;
+use sui_core::authority_aggregator::AuthorityAggregatorBuilder;
 use sui_core::authority_client::AuthorityAPI;
 
 use sui_types::{base_types::*, object::Owner};
@@ -78,6 +79,19 @@ pub enum ToolCommand {
         concise_no_header: bool,
     },
 
+    /// Fetch an object at a specific version, as agreed on by f+1 of the validators
+    #[clap(name = "fetch-past-object")]
+    FetchPastObject {
+        #[clap(long, help = "The object ID to fetch")]
+        id: ObjectID,
+
+        #[clap(long, help = "The version of the object to fetch")]
+        version: u64,
+
+        #[clap(long = "genesis")]
+        genesis: PathBuf,
+    },
+
     #[clap(name = "fetch-transaction")]
     FetchTransaction {
         #[clap(long = "genesis")]
@@ -215,6 +229,18 @@ impl ToolCommand {
                     }
                 }
             }
+            ToolCommand::FetchPastObject {
+                id,
+                version,
+                genesis,
+            } => {
+                let genesis = Genesis::load(genesis)?;
+                let (aggregator, _) = AuthorityAggregatorBuilder::from_genesis(&genesis).build()?;
+                let read = aggregator
+                    .get_past_object_info_debug(id, SequenceNumber::from_u64(version))
+                    .await?;
+                println!("{:#?}", read);
+            }
             ToolCommand::FetchTransaction { genesis, digest } => {
                 print!("{}", get_transaction(digest, genesis).await?);
             }
@@ -8,6 +8,7 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use futures::future::join_all;
+use futures::stream::{self, StreamExt};
 
 use anyhow::{anyhow, ensure};
 use futures::try_join;
@@ -41,6 +42,13 @@ use sui_types::{
     SUI_SYSTEM_STATE_OBJECT_SHARED_VERSION,
 };
 
+#[cfg(test)]
+#[path = "unit_tests/transaction_builder_tests.rs"]
+mod transaction_builder_tests;
+
+/// Maximum number of gas coins fetched concurrently while selecting gas.
+const MAX_CONCURRENT_GAS_COIN_FETCHES: usize = 16;
+
 #[async_trait]
 pub trait DataReader {
     async fn get_objects_owned_by_address(
@@ -67,6 +75,10 @@ impl<Mode: ExecutionMode> TransactionBuilder<Mode> {
         Self(data_reader, PhantomData)
     }
 
+    /// Returns `input_gas` if one is given. Otherwise picks the smallest of the signer's gas
+    /// coins that covers `budget * gas_price`, so that large coins are not fragmented by paying
+    /// for small transactions. This reads every gas coin the signer owns, so its cost grows
+    /// with the number of coins held by the address.
     async fn select_gas(
         &self,
         signer: SuiAddress,
@@ -76,30 +88,47 @@ impl<Mode: ExecutionMode> TransactionBuilder<Mode> {
         gas_price: u64,
     ) -> Result<ObjectRef, anyhow::Error> {
         if let Some(gas) = input_gas {
-            self.get_object_ref(gas).await
+            ensure!(
+                !input_objects.contains(&gas),
+                "Gas object [{gas}] is also used as an input object of the transaction."
+            );
+            self.get_owned_object_ref(signer, gas).await
         } else {
             let objs = self.0.get_objects_owned_by_address(signer).await?;
-            let gas_objs = objs
-                .iter()
-                .filter(|obj| obj.type_ == GasCoin::type_().to_string());
+            let gas_objs = objs.iter().filter(|obj| {
+                obj.type_ == GasCoin::type_().to_string() && !input_objects.contains(&obj.object_id)
+            });
             let required_gas_amount = (budget as u128) * (gas_price as u128);
 
-            for obj in gas_objs {
-                let response = self.0.get_object(obj.object_id).await?;
-                let obj = response.object()?;
-                let gas: GasCoin = bcs::from_bytes(
-                    &obj.data
-                        .try_as_move()
-                        .ok_or_else(|| anyhow!("Cannot parse move object to gas object"))?
-                        .bcs_bytes,
-                )?;
-                if !input_objects.contains(&obj.id())
-                    && (gas.value() as u128) >= required_gas_amount
+            let mut responses = stream::iter(gas_objs.map(|obj| self.0.get_object(obj.object_id)))
+                .buffer_unordered(MAX_CONCURRENT_GAS_COIN_FETCHES);
+            let mut selected: Option<(u64, ObjectRef)> = None;
+            let mut largest_balance = 0;
+            while let Some(response) = responses.next().await {
+                // A coin may be spent or deleted while we scan, so skip the ones we cannot read
+                // or parse rather than failing the whole selection.
+                let coin = response
+                    .ok()
+                    .and_then(|response| response.into_object().ok())
+                    .and_then(|obj| {
+                        let gas: GasCoin =
+                            bcs::from_bytes(&obj.data.try_as_move()?.bcs_bytes).ok()?;
+                        Some((gas.value(), obj.reference.to_object_ref()))
+                    });
+                let (value, gas_ref) = match coin {
+                    Some(coin) => coin,
+                    None => continue,
+                };
+                largest_balance = largest_balance.max(value);
+                if (value as u128) >= required_gas_amount
+                    && selected.map_or(true, |(balance, _)| value < balance)
                 {
-                    return Ok(obj.reference.to_object_ref());
+                    selected = Some((value, gas_ref));
                 }
             }
-            Err(anyhow!("Cannot find gas coin for signer address [{signer}] with amount sufficient for the required gas amount [{required_gas_amount}]."))
+            selected.map(|(_, gas)| gas).ok_or_else(|| {
+                anyhow!("Cannot find gas coin for signer address [{signer}] with amount sufficient for the required gas amount [{required_gas_amount}]. The largest available gas coin balance is [{largest_balance}].")
+            })
         }
     }
 
@@ -112,7 +141,7 @@ impl<Mode: ExecutionMode> TransactionBuilder<Mode> {
         recipient: SuiAddress,
     ) -> anyhow::Result<TransactionData> {
         let (single_transfer, gas_price) = try_join!(
-            self.single_transfer_object(object_id, recipient),
+            self.single_transfer_object(signer, object_id, recipient),
             self.0.get_reference_gas_price()
         )?;
         let gas = self
@@ -130,12 +159,13 @@ impl<Mode: ExecutionMode> TransactionBuilder<Mode> {
 
     async fn single_transfer_object(
         &self,
+        signer: SuiAddress,
         object_id: ObjectID,
         recipient: SuiAddress,
     ) -> Result<SingleTransactionKind, anyhow::Error> {
         Ok(SingleTransactionKind::TransferObject(TransferObject {
             recipient,
-            object_ref: self.get_object_ref(object_id).await?,
+            object_ref: self.get_owned_object_ref(signer, object_id).await?,
         }))
     }
 
@@ -556,7 +586,7 @@ impl<Mode: ExecutionMode> TransactionBuilder<Mode> {
         for param in single_transaction_params {
             let single_tx = match param {
                 RPCTransactionRequestParams::TransferObjectRequestParams(param) => {
-                    self.single_transfer_object(param.object_id, param.recipient)
+                    self.single_transfer_object(signer, param.object_id, param.recipient)
                         .await?
                 }
                 RPCTransactionRequestParams::MoveCallRequestParams(param) => {
@@ -751,6 +781,26 @@ impl<Mode: ExecutionMode> TransactionBuilder<Mode> {
             .map(|(oref, _)| oref)
     }
 
+    /// Like `get_object_ref`, but fails with `SuiError::NotOwner` unless the object is owned by
+    /// `signer`, so callers get an actionable error before the transaction reaches validators.
+    async fn get_owned_object_ref(
+        &self,
+        signer: SuiAddress,
+        object_id: ObjectID,
+    ) -> anyhow::Result<ObjectRef> {
+        let object = self.0.get_object(object_id).await?.into_object()?;
+        fp_ensure!(
+            object.owner == Owner::AddressOwner(signer),
+            SuiError::NotOwner {
+                object_id,
+                owner: object.owner,
+                signer,
+            }
+            .into()
+        );
+        Ok(object.reference.to_object_ref())
+    }
+
     async fn get_object_ref_and_type(
         &self,
         object_id: ObjectID,
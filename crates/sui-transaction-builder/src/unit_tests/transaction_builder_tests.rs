@@ -0,0 +1,84 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use sui_adapter::execution_mode::Normal;
+use sui_types::base_types::ObjectInfo;
+
+use super::*;
+
+/// A DataReader over a fixed set of gas coins. `stale` coins are listed as owned but can no
+/// longer be read, as if they were deleted between the two calls.
+struct MockDataReader {
+    objects: BTreeMap<ObjectID, Object>,
+    stale: Vec<Object>,
+}
+
+#[async_trait]
+impl DataReader for MockDataReader {
+    async fn get_objects_owned_by_address(
+        &self,
+        address: SuiAddress,
+    ) -> Result<Vec<SuiObjectInfo>, anyhow::Error> {
+        Ok(self
+            .objects
+            .values()
+            .chain(&self.stale)
+            .filter(|o| o.owner == Owner::AddressOwner(address))
+            .map(|o| ObjectInfo::new(&o.compute_object_reference(), o).into())
+            .collect())
+    }
+
+    async fn get_object(
+        &self,
+        object_id: ObjectID,
+    ) -> Result<GetRawObjectDataResponse, anyhow::Error> {
+        Ok(match self.objects.get(&object_id) {
+            Some(o) => GetRawObjectDataResponse::Exists(SuiObject::try_from(
+                o.clone(),
+                Some(GasCoin::layout()),
+            )?),
+            None => GetRawObjectDataResponse::NotExists(object_id),
+        })
+    }
+
+    async fn get_reference_gas_price(&self) -> Result<u64, anyhow::Error> {
+        Ok(1)
+    }
+}
+
+#[tokio::test]
+async fn test_select_gas_picks_smallest_sufficient_coin() {
+    let owner = SuiAddress::random_for_testing_only();
+    let coins: Vec<_> = [100, 500, 1000, 300]
+        .into_iter()
+        .map(|value| Object::new_gas_with_balance_and_owner_for_testing(value, owner))
+        .collect();
+    let stale = Object::new_gas_with_balance_and_owner_for_testing(300, owner);
+    let builder = TransactionBuilder::<Normal>::new(Arc::new(MockDataReader {
+        objects: coins.iter().map(|o| (o.id(), o.clone())).collect(),
+        stale: vec![stale],
+    }));
+
+    // The 300 coin is the smallest that covers the budget; the unreadable coin is skipped.
+    let gas = builder
+        .select_gas(owner, None, 300, vec![], 1)
+        .await
+        .unwrap();
+    assert_eq!(gas, coins[3].compute_object_reference());
+
+    // Coins used as transaction inputs are never picked for gas.
+    let gas = builder
+        .select_gas(owner, None, 300, vec![coins[3].id()], 1)
+        .await
+        .unwrap();
+    assert_eq!(gas, coins[1].compute_object_reference());
+
+    // When no coin is large enough, the error reports the largest balance available.
+    let err = builder
+        .select_gas(owner, None, 2000, vec![], 1)
+        .await
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("The largest available gas coin balance is [1000]"));
+}
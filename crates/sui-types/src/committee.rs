@@ -227,6 +227,21 @@ impl Committee {
         }
     }
 
+    pub fn total_stake(&self) -> StakeUnit {
+        self.total_votes
+    }
+
+    /// Total stake of the given authorities. Each authority is counted once, and names that
+    /// are not members of the committee contribute nothing.
+    pub fn stake_for(&self, authorities: &[AuthorityName]) -> StakeUnit {
+        authorities
+            .iter()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .map(|name| self.weight(name))
+            .sum()
+    }
+
     pub fn quorum_threshold(&self) -> StakeUnit {
         // If N = 3f + 1 + k (0 <= k < 3)
         // then (2 N + 3) / 3 = 2f + 1 + (2k + 2)/3 = 2f + 1 + k = N - f
@@ -483,4 +498,40 @@ mod test {
             (a3, "c")
         );
     }
+
+    #[test]
+    fn test_stake_accounting_with_uneven_stake() {
+        let (_, sec1): (_, AuthorityKeyPair) = get_key_pair();
+        let (_, sec2): (_, AuthorityKeyPair) = get_key_pair();
+        let (_, sec3): (_, AuthorityKeyPair) = get_key_pair();
+        let (_, sec4): (_, AuthorityKeyPair) = get_key_pair();
+        let (_, sec5): (_, AuthorityKeyPair) = get_key_pair();
+        let a1: AuthorityName = sec1.public().into();
+        let a2: AuthorityName = sec2.public().into();
+        let a3: AuthorityName = sec3.public().into();
+        let a4: AuthorityName = sec4.public().into();
+        let outsider: AuthorityName = sec5.public().into();
+
+        let mut authorities = BTreeMap::new();
+        authorities.insert(a1, 5000);
+        authorities.insert(a2, 2500);
+        authorities.insert(a3, 1500);
+        authorities.insert(a4, 1000);
+        let committee = Committee::new(0, ProtocolVersion::MIN, authorities).unwrap();
+
+        assert_eq!(committee.total_stake(), 10000);
+        assert_eq!(committee.quorum_threshold(), 6667);
+        assert_eq!(committee.validity_threshold(), 3334);
+
+        assert_eq!(committee.stake_for(&[]), 0);
+        assert_eq!(committee.stake_for(&[a1]), 5000);
+        // Duplicates and non-members do not add stake.
+        assert_eq!(committee.stake_for(&[a1, a1, outsider]), 5000);
+        // The largest validator alone reaches validity but not quorum.
+        assert!(committee.stake_for(&[a1]) >= committee.validity_threshold());
+        assert!(committee.stake_for(&[a1]) < committee.quorum_threshold());
+        assert!(committee.stake_for(&[a1, a3]) < committee.quorum_threshold());
+        assert!(committee.stake_for(&[a1, a2]) >= committee.quorum_threshold());
+        assert!(committee.stake_for(&[a2, a3, a4]) < committee.quorum_threshold());
+    }
 }
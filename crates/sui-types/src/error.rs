@@ -547,6 +547,21 @@ pub enum SuiError {
 
     #[error("unknown error: {0}")]
     Unknown(String),
+
+    #[error("Object {object_id} is owned by {owner}, not by signer {signer}")]
+    NotOwner {
+        object_id: ObjectID,
+        owner: Owner,
+        signer: SuiAddress,
+    },
+
+    #[error(
+        "Object {object_id} existed at version {version:?}, but validators no longer keep its contents"
+    )]
+    ObjectVersionPruned {
+        object_id: ObjectID,
+        version: SequenceNumber,
+    },
 }
 
 #[repr(u64)]
@@ -2026,6 +2026,15 @@ impl TransactionEffects {
         }
     }
 
+    /// Return the references of all objects that are owned by `owner` after this transaction,
+    /// i.e. created, mutated or unwrapped objects whose owner is that address.
+    pub fn objects_owned_by(&self, owner: SuiAddress) -> Vec<ObjectRef> {
+        self.all_mutated()
+            .filter(|(_, o, _)| **o == Owner::AddressOwner(owner))
+            .map(|(r, _, _)| *r)
+            .collect()
+    }
+
     /// Return an iterator of mutated objects, but excluding the gas object.
     pub fn mutated_excluding_gas(&self) -> impl Iterator<Item = &(ObjectRef, Owner)> {
         self.mutated.iter().filter(|o| *o != &self.gas_object)
@@ -712,12 +712,39 @@ impl ObjectRead {
     /// the object does not exist or is deleted.
     pub fn into_object(self) -> Result<Object, SuiError> {
         match self {
-            Self::Deleted(oref) => Err(SuiError::ObjectDeleted { object_ref: oref }),
-            Self::NotExists(id) => Err(SuiError::ObjectNotFound {
-                object_id: id,
-                version: None,
-            }),
             Self::Exists(_, o, _) => Ok(o),
+            _ => Err(self.missing_object_error()),
+        }
+    }
+
+    /// Returns a reference to the object value if there is any, otherwise an Err if
+    /// the object does not exist or is deleted.
+    pub fn object(&self) -> Result<&Object, SuiError> {
+        match self {
+            Self::Exists(_, o, _) => Ok(o),
+            _ => Err(self.missing_object_error()),
+        }
+    }
+
+    /// Returns the object reference if the object exists, otherwise an Err if
+    /// the object does not exist or is deleted.
+    pub fn reference(&self) -> Result<ObjectRef, SuiError> {
+        match self {
+            Self::Exists(oref, _, _) => Ok(*oref),
+            _ => Err(self.missing_object_error()),
+        }
+    }
+
+    /// The error for a read that found no object, carrying the object id and whether the
+    /// object was deleted or never existed.
+    fn missing_object_error(&self) -> SuiError {
+        match self {
+            Self::Deleted(oref) => SuiError::ObjectDeleted { object_ref: *oref },
+            Self::NotExists(id) => SuiError::ObjectNotFound {
+                object_id: *id,
+                version: None,
+            },
+            Self::Exists(oref, _, _) => unreachable!("object {:?} exists", oref),
         }
     }
 }
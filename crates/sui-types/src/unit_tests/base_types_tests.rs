@@ -383,3 +383,31 @@ fn test_address_backwards_compatibility() {
                also require updates if they use fixed values generated by the old algorithm."
     );
 }
+
+#[test]
+fn test_object_read_accessors() {
+    use crate::object::ObjectRead;
+
+    let (owner, _): (_, AccountKeyPair) = get_key_pair();
+    let object = Object::with_owner_for_testing(owner);
+    let object_ref = object.compute_object_reference();
+    let read = ObjectRead::Exists(object_ref, object.clone(), None);
+    assert_eq!(read.reference().unwrap(), object_ref);
+    assert_eq!(read.object().unwrap(), &object);
+    assert_eq!(read.into_object().unwrap(), object);
+
+    let read = ObjectRead::Deleted(object_ref);
+    let expected = SuiError::ObjectDeleted { object_ref };
+    assert_eq!(read.reference().unwrap_err(), expected);
+    assert_eq!(read.object().unwrap_err(), expected);
+    assert_eq!(read.into_object().unwrap_err(), expected);
+
+    let read = ObjectRead::NotExists(object_ref.0);
+    let expected = SuiError::ObjectNotFound {
+        object_id: object_ref.0,
+        version: None,
+    };
+    assert_eq!(read.reference().unwrap_err(), expected);
+    assert_eq!(read.object().unwrap_err(), expected);
+    assert_eq!(read.into_object().unwrap_err(), expected);
+}
@@ -504,6 +504,36 @@ fn test_digest_caching() {
     assert_ne!(initial_effects_digest, *deserialized_effects.digest());
 }
 
+#[test]
+fn test_effects_objects_owned_by() {
+    let (sender, _): (_, AccountKeyPair) = get_key_pair();
+    let (recipient, _): (_, AccountKeyPair) = get_key_pair();
+
+    let created = random_object_ref();
+    let mutated_kept = random_object_ref();
+    let transferred_away = random_object_ref();
+    let gas = random_object_ref();
+    let deleted = random_object_ref();
+    let effects = TransactionEffects {
+        created: vec![(created, Owner::AddressOwner(sender))],
+        mutated: vec![
+            (mutated_kept, Owner::AddressOwner(sender)),
+            (transferred_away, Owner::AddressOwner(recipient)),
+            (gas, Owner::AddressOwner(sender)),
+        ],
+        deleted: vec![deleted],
+        gas_object: (gas, Owner::AddressOwner(sender)),
+        ..Default::default()
+    };
+
+    let mut owned = effects.objects_owned_by(sender);
+    owned.sort();
+    let mut expected = vec![created, mutated_kept, gas];
+    expected.sort();
+    assert_eq!(owned, expected);
+    assert_eq!(effects.objects_owned_by(recipient), vec![transferred_away]);
+}
+
 #[test]
 fn test_user_signature_committed_in_transactions() {
     // TODO: refactor this test to not reuse the same keys for user and authority signing
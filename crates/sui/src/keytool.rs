@@ -20,7 +20,9 @@ use tracing::info;
 
 use sui_keys::keystore::{AccountKeystore, Keystore};
 use sui_types::base_types::SuiAddress;
-use sui_types::crypto::{get_authority_key_pair, EncodeDecodeBase64, SignatureScheme, SuiKeyPair};
+use sui_types::crypto::{
+    get_authority_key_pair, EncodeDecodeBase64, PublicKey, SignatureScheme, SuiKeyPair,
+};
 #[cfg(test)]
 #[path = "unit_tests/keytool_tests.rs"]
 mod keytool_tests;
@@ -51,6 +53,10 @@ pub enum KeyToolCommand {
     /// List all keys by its Sui address, Base64 encoded public key, key scheme name in
     /// sui.keystore.
     List,
+    /// This takes a Base64 encoded 33-byte `flag || pubkey` (or 34-byte for secp256k1 and
+    /// secp256r1) public key and prints the Sui address derived from it. The private key is not
+    /// needed.
+    Address { public_key: String },
     /// Create signature using the private key for for the given address in sui keystore.
     /// Any signature commits to a [struct IntentMessage] consisting of the Base64 encoded
     /// of the BCS serialized transaction bytes itself (the result of
@@ -143,6 +149,11 @@ impl KeyToolCommand {
                     );
                 }
             }
+            KeyToolCommand::Address { public_key } => {
+                let public_key = PublicKey::decode_base64(&public_key)
+                    .map_err(|e| anyhow!("Invalid public key [{public_key}]: {e}"))?;
+                println!("Sui Address: {}", SuiAddress::from(&public_key));
+            }
             KeyToolCommand::Sign {
                 address,
                 data,
@@ -131,6 +131,7 @@ impl SuiCommand {
                             network_config_path
                         ))
                     })?;
+                network_config.validate_ports_available()?;
 
                 let mut swarm = if no_full_node {
                     Swarm::builder()
@@ -49,6 +49,33 @@ fn test_addresses_command() -> Result<(), anyhow::Error> {
     Ok(())
 }
 
+#[test]
+fn test_address_command() -> Result<(), anyhow::Error> {
+    let mut keystore = Keystore::from(InMemKeystore::new(0));
+    for kp in [
+        SuiKeyPair::Ed25519(get_key_pair().1),
+        SuiKeyPair::Secp256k1(get_key_pair().1),
+    ] {
+        KeyToolCommand::Address {
+            public_key: kp.public().encode_base64(),
+        }
+        .execute(&mut keystore)?;
+    }
+
+    // Malformed keys are rejected.
+    assert!(KeyToolCommand::Address {
+        public_key: "not base64".to_string(),
+    }
+    .execute(&mut keystore)
+    .is_err());
+    assert!(KeyToolCommand::Address {
+        public_key: Base64::encode([0u8; 10]),
+    }
+    .execute(&mut keystore)
+    .is_err());
+    Ok(())
+}
+
 #[test]
 fn test_flag_in_signature_and_keypair() -> Result<(), anyhow::Error> {
     let mut keystore = Keystore::from(InMemKeystore::new(0));